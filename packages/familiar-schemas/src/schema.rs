@@ -121,6 +121,42 @@ impl Schema {
     pub fn filename(&self) -> String {
         format!("{}.{}", self.name, self.schema_type.extension())
     }
+
+    /// Render the content as canonical, diff-friendly JSON
+    ///
+    /// Object keys are sorted at every level (which also keeps all
+    /// `x-familiar-*` extensions adjacent), output is pretty-printed with
+    /// two-space indentation and ends with a single trailing newline.
+    ///
+    /// Lives on `Schema` rather than a graph-level `canonicalize(id)` since
+    /// this crate carries no schema graph.
+    pub fn canonical_json(&self) -> String {
+        let canonical = canonicalize(&self.content);
+        let mut out = serde_json::to_string_pretty(&canonical)
+            .expect("serializing a serde_json::Value is infallible");
+        out.push('\n');
+        out
+    }
+}
+
+/// Recursively rebuild a JSON value with object keys in sorted order
+// A no-op while `serde_json::Map` is a `BTreeMap`; needed if `preserve_order` is unified in.
+fn canonicalize(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut sorted = serde_json::Map::new();
+            for key in keys {
+                sorted.insert(key.clone(), canonicalize(&map[key]));
+            }
+            serde_json::Value::Object(sorted)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(canonicalize).collect())
+        }
+        other => other.clone(),
+    }
 }
 
 /// A versioned schema entry in the registry
@@ -282,3 +318,32 @@ impl VersionManifest {
         self.schemas.iter().filter(|s| s.schema.category == category).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_json_is_stable_and_sorted() {
+        let content: serde_json::Value = serde_json::from_str(
+            r#"{"x-familiar-kind": "entity", "type": "object", "title": "User", "x-familiar-service": "auth", "properties": {"name": {"type": "string"}, "id": {"type": "string"}}}"#,
+        )
+        .unwrap();
+        let schema = Schema::new("User", SchemaType::JsonSchema, content);
+
+        let first = schema.canonical_json();
+        let second = schema.canonical_json();
+        assert_eq!(first, second);
+        assert!(first.ends_with("}\n"));
+
+        // Match keys at their indent so nested keys aren't confused with top-level ones
+        let pos = |indent: usize, key: &str| {
+            first.find(&format!("\n{}\"{}\"", " ".repeat(indent), key)).unwrap()
+        };
+        assert!(pos(2, "properties") < pos(2, "title"));
+        assert!(pos(2, "title") < pos(2, "type"));
+        assert!(pos(2, "type") < pos(2, "x-familiar-kind"));
+        assert!(pos(2, "x-familiar-kind") < pos(2, "x-familiar-service"));
+        assert!(pos(4, "id") < pos(4, "name"));
+    }
+}
@@ -61,6 +61,11 @@ impl SchemaVersion {
         self.tag_string()
     }
 
+    /// Check if this is a pre-release version (e.g., "1.2.0-rc.1")
+    pub fn is_prerelease(&self) -> bool {
+        !self.version.pre.is_empty()
+    }
+
     /// Check if this is a major version bump from another version
     pub fn is_major_bump_from(&self, other: &SchemaVersion) -> bool {
         self.version.major > other.version.major
@@ -79,14 +84,27 @@ impl SchemaVersion {
             && self.version.patch > other.version.patch
     }
 
+    /// The release this version belongs to, without pre-release or build metadata
+    fn release(&self) -> Version {
+        Version::new(self.version.major, self.version.minor, self.version.patch)
+    }
+
     /// Bump major version
+    ///
+    /// A pre-release that already sits on the next major release
+    /// (e.g., "2.0.0-rc.1") is promoted to that release.
     pub fn bump_major(&self) -> Self {
         let mut new_version = self.clone();
-        new_version.version = Version::new(
-            self.version.major + 1,
-            0,
-            0,
-        );
+        let on_next_major = self.version.minor == 0 && self.version.patch == 0;
+        new_version.version = if self.is_prerelease() && on_next_major {
+            self.release()
+        } else {
+            Version::new(
+                self.version.major + 1,
+                0,
+                0,
+            )
+        };
         new_version.previous_version = Some(self.version_string());
         new_version.created_at = Utc::now();
         new_version.commit_hash = None;
@@ -95,13 +113,20 @@ impl SchemaVersion {
     }
 
     /// Bump minor version
+    ///
+    /// A pre-release that already sits on the next minor release
+    /// (e.g., "1.3.0-rc.1") is promoted to that release.
     pub fn bump_minor(&self) -> Self {
         let mut new_version = self.clone();
-        new_version.version = Version::new(
-            self.version.major,
-            self.version.minor + 1,
-            0,
-        );
+        new_version.version = if self.is_prerelease() && self.version.patch == 0 {
+            self.release()
+        } else {
+            Version::new(
+                self.version.major,
+                self.version.minor + 1,
+                0,
+            )
+        };
         new_version.previous_version = Some(self.version_string());
         new_version.created_at = Utc::now();
         new_version.commit_hash = None;
@@ -110,13 +135,19 @@ impl SchemaVersion {
     }
 
     /// Bump patch version
+    ///
+    /// A pre-release (e.g., "1.2.3-rc.1") is promoted to its release.
     pub fn bump_patch(&self) -> Self {
         let mut new_version = self.clone();
-        new_version.version = Version::new(
-            self.version.major,
-            self.version.minor,
-            self.version.patch + 1,
-        );
+        new_version.version = if self.is_prerelease() {
+            self.release()
+        } else {
+            Version::new(
+                self.version.major,
+                self.version.minor,
+                self.version.patch + 1,
+            )
+        };
         new_version.previous_version = Some(self.version_string());
         new_version.created_at = Utc::now();
         new_version.commit_hash = None;
@@ -131,9 +162,11 @@ impl fmt::Display for SchemaVersion {
     }
 }
 
+/// Versions compare by semver precedence: pre-releases sort before their
+/// release and build metadata is ignored.
 impl PartialEq for SchemaVersion {
     fn eq(&self, other: &Self) -> bool {
-        self.version == other.version
+        self.cmp(other) == std::cmp::Ordering::Equal
     }
 }
 
@@ -147,7 +180,10 @@ impl PartialOrd for SchemaVersion {
 
 impl Ord for SchemaVersion {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.version.cmp(&other.version)
+        self.version.major.cmp(&other.version.major)
+            .then(self.version.minor.cmp(&other.version.minor))
+            .then(self.version.patch.cmp(&other.version.patch))
+            .then_with(|| self.version.pre.cmp(&other.version.pre))
     }
 }
 
@@ -181,12 +217,46 @@ mod tests {
         let patch = v.bump_patch();
         assert_eq!(patch.version_string(), "1.2.4");
     }
-}
-
 
+    #[test]
+    fn test_prerelease_ordering() {
+        let rc1 = SchemaVersion::parse("0.2.0-rc.1").unwrap();
+        let rc2 = SchemaVersion::parse("0.2.0-rc.2").unwrap();
+        let release = SchemaVersion::parse("0.2.0").unwrap();
 
+        assert!(rc1.is_prerelease());
+        assert!(!release.is_prerelease());
+        assert!(rc1 < rc2);
+        assert!(rc2 < release);
+        assert!(SchemaVersion::parse("0.1.9").unwrap() < rc1);
+    }
 
+    #[test]
+    fn test_build_metadata_ignored() {
+        let a = SchemaVersion::parse("1.0.0+build.1").unwrap();
+        let b = SchemaVersion::parse("1.0.0+build.2").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    }
 
+    #[test]
+    fn test_prerelease_bumps() {
+        let v = SchemaVersion::parse("1.3.0-rc.1").unwrap();
+        assert_eq!(v.bump_patch().version_string(), "1.3.0");
+        assert_eq!(v.bump_minor().version_string(), "1.3.0");
+        assert_eq!(v.bump_major().version_string(), "2.0.0");
+        assert_eq!(v.bump_minor().previous_version.as_deref(), Some("1.3.0-rc.1"));
 
+        let v = SchemaVersion::parse("1.2.3-rc.1").unwrap();
+        assert_eq!(v.bump_minor().version_string(), "1.3.0");
 
+        let v = SchemaVersion::parse("2.0.0-rc.1").unwrap();
+        assert_eq!(v.bump_major().version_string(), "2.0.0");
+        assert_eq!(v.bump_minor().version_string(), "2.0.0");
+        assert_eq!(v.bump_patch().version_string(), "2.0.0");
 
+        let v = SchemaVersion::parse("1.0.0-rc.1").unwrap();
+        assert_eq!(v.bump_major().version_string(), "1.0.0");
+        assert!(!v.bump_major().is_prerelease());
+    }
+}